@@ -6,6 +6,9 @@
 use core::ops::{Add, Mul, Sub};
 use fixed::types::*;
 use heapless::Vec;
+
+mod ops;
+
 #[allow(unused_macros)]
 macro_rules! fixed {
     ($val:expr) => {
@@ -24,7 +27,80 @@ impl Vertex {
         let xx: I32F32 = I32F32::from_num(self.x) * I32F32::from_num(self.x);
         let yy: I32F32 = I32F32::from_num(self.y) * I32F32::from_num(self.y);
         let sqr = xx + yy;
-        I16F16::from_num(cordic::sqrt(sqr))
+        I16F16::from_num(ops::sqrt(sqr))
+    }
+    /// Inner (dot) product, widened to `I32F32` to limit precision loss.
+    pub fn dot(self, rhs: Vertex) -> I16F16 {
+        let xx: I32F32 = I32F32::from_num(self.x) * I32F32::from_num(rhs.x);
+        let yy: I32F32 = I32F32::from_num(self.y) * I32F32::from_num(rhs.y);
+        I16F16::from_num(xx + yy)
+    }
+    /// The scalar z component of the 2D cross product (a.k.a. the perp dot
+    /// product), useful for winding/turn direction.
+    pub fn cross(self, rhs: Vertex) -> I16F16 {
+        let xy: I32F32 = I32F32::from_num(self.x) * I32F32::from_num(rhs.y);
+        let yx: I32F32 = I32F32::from_num(self.y) * I32F32::from_num(rhs.x);
+        I16F16::from_num(xy - yx)
+    }
+    /// Euclidean distance to another `Vertex`.
+    pub fn distance(self, rhs: Vertex) -> I16F16 {
+        (self - rhs).abs()
+    }
+    /// Unit vector in the same direction, or `None` if `self` has zero length.
+    pub fn normalize(self) -> Option<Vertex> {
+        let len = self.abs();
+        if len == fixed!(0.0) {
+            return None;
+        }
+        Some(Vertex {
+            x: self.x / len,
+            y: self.y / len,
+        })
+    }
+    /// Orthogonal projection of `self` onto `rhs`, or `None` if `rhs` has zero length.
+    pub fn project_on(self, rhs: Vertex) -> Option<Vertex> {
+        let denom = rhs.dot(rhs);
+        if denom == fixed!(0.0) {
+            return None;
+        }
+        Some(rhs * (self.dot(rhs) / denom))
+    }
+    /// Phase of `self` relative to the origin, i.e. `atan2(y, x)`.
+    pub fn angle(self) -> Rad {
+        Rad(ops::atan2(self.y, self.x))
+    }
+    /// The unit vector at the given angle, measured counter-clockwise from the x axis.
+    pub fn from_angle(angle: Rad) -> Vertex {
+        let (sin, cos) = ops::sin_cos(angle.0);
+        Vertex { x: cos, y: sin }
+    }
+    /// `self` rotated counter-clockwise by `angle`.
+    pub fn rotate(self, angle: Rad) -> Vertex {
+        let (sin, cos) = ops::sin_cos(angle.0);
+        Vertex {
+            x: self.x * cos - self.y * sin,
+            y: self.x * sin + self.y * cos,
+        }
+    }
+}
+
+/// An angle in radians, stored as fixed-point `I16F16`.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Rad(pub I16F16);
+
+/// An angle in degrees, stored as fixed-point `I16F16`.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Deg(pub I16F16);
+
+impl From<Deg> for Rad {
+    fn from(deg: Deg) -> Self {
+        Rad(deg.0 * fixed!(core::f32::consts::PI) / fixed!(180.0))
+    }
+}
+
+impl From<Rad> for Deg {
+    fn from(rad: Rad) -> Self {
+        Deg(rad.0 * fixed!(180.0) / fixed!(core::f32::consts::PI))
     }
 }
 
@@ -36,6 +112,23 @@ impl Circle {
             r: fixed!(r),
         }
     }
+    /// The point on the circle at the given phase, measured counter-clockwise from the x axis.
+    pub fn point_at(self, angle: Rad) -> Vertex {
+        Vertex { x: self.x, y: self.y } + Vertex::from_angle(angle) * self.r
+    }
+    /// The axis-aligned bounding box of the circle.
+    pub fn bounding_box(self) -> Aabb2 {
+        Aabb2 {
+            min: Vertex {
+                x: self.x - self.r,
+                y: self.y - self.r,
+            },
+            max: Vertex {
+                x: self.x + self.r,
+                y: self.y + self.r,
+            },
+        }
+    }
     pub fn exp_filt(self, rhs: Circle, alpha: I16F16) -> Self {
         let xx = self.x * (fixed!(1.0) - alpha) + rhs.x * alpha;
         let yy = self.y * (fixed!(1.0) - alpha) + rhs.y * alpha;
@@ -67,6 +160,42 @@ pub struct Circle {
     pub r: I16F16,
 }
 
+/// Axis-aligned bounding box, for cheap rejection before a more expensive fit or containment check.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct Aabb2 {
+    pub min: Vertex,
+    pub max: Vertex,
+}
+
+impl Aabb2 {
+    /// Folds `min`/`max` over a buffer of samples. `None` if the buffer is empty.
+    pub fn from_vertices<const N: usize>(vertex: &Vec<Vertex, N>) -> Option<Aabb2> {
+        let mut iter = vertex.iter();
+        let first = *iter.next()?;
+        let mut aabb = Aabb2 {
+            min: first,
+            max: first,
+        };
+        for v in iter {
+            aabb.min.x = aabb.min.x.min(v.x);
+            aabb.min.y = aabb.min.y.min(v.y);
+            aabb.max.x = aabb.max.x.max(v.x);
+            aabb.max.y = aabb.max.y.max(v.y);
+        }
+        Some(aabb)
+    }
+    /// Whether `v` lies within the box, bounds inclusive.
+    pub fn contains(self, v: Vertex) -> bool {
+        v.x >= self.min.x && v.x <= self.max.x && v.y >= self.min.y && v.y <= self.max.y
+    }
+    pub fn center(self) -> Vertex {
+        Vertex {
+            x: (self.min.x + self.max.x) / fixed!(2.0),
+            y: (self.min.y + self.max.y) / fixed!(2.0),
+        }
+    }
+}
+
 impl Mul<I16F16> for Vertex {
     // The multiplication of rational numbers is a closed operation.
     type Output = Self;
@@ -116,6 +245,69 @@ pub trait ExpFilt<T, F> {
     fn exp_filt(self, rhs: T, alpha: F) -> Self;
 }
 
+/// Tolerance-based equality: are two values within `epsilon` of each other.
+pub trait AbsDiffEq {
+    fn abs_diff_eq(self, other: Self, epsilon: I16F16) -> bool;
+}
+
+/// Tolerance-based equality that scales with the magnitude of the values
+/// being compared, for cases where a fixed `epsilon` is too tight for large
+/// values and too loose for small ones.
+pub trait RelativeEq: AbsDiffEq {
+    fn relative_eq(self, other: Self, epsilon: I16F16, max_relative: I16F16) -> bool;
+}
+
+impl AbsDiffEq for Vertex {
+    fn abs_diff_eq(self, other: Self, epsilon: I16F16) -> bool {
+        (self.x - other.x).abs() <= epsilon && (self.y - other.y).abs() <= epsilon
+    }
+}
+
+impl RelativeEq for Vertex {
+    fn relative_eq(self, other: Self, epsilon: I16F16, max_relative: I16F16) -> bool {
+        let dx = (self.x - other.x).abs();
+        let dy = (self.y - other.y).abs();
+        let scale_x = self.x.abs().max(other.x.abs());
+        let scale_y = self.y.abs().max(other.y.abs());
+        (dx <= epsilon || dx <= scale_x * max_relative) && (dy <= epsilon || dy <= scale_y * max_relative)
+    }
+}
+
+impl AbsDiffEq for Circle {
+    fn abs_diff_eq(self, other: Self, epsilon: I16F16) -> bool {
+        (self.x - other.x).abs() <= epsilon
+            && (self.y - other.y).abs() <= epsilon
+            && (self.r - other.r).abs() <= epsilon
+    }
+}
+
+impl RelativeEq for Circle {
+    fn relative_eq(self, other: Self, epsilon: I16F16, max_relative: I16F16) -> bool {
+        let dx = (self.x - other.x).abs();
+        let dy = (self.y - other.y).abs();
+        let dr = (self.r - other.r).abs();
+        let scale_x = self.x.abs().max(other.x.abs());
+        let scale_y = self.y.abs().max(other.y.abs());
+        let scale_r = self.r.abs().max(other.r.abs());
+        (dx <= epsilon || dx <= scale_x * max_relative)
+            && (dy <= epsilon || dy <= scale_y * max_relative)
+            && (dr <= epsilon || dr <= scale_r * max_relative)
+    }
+}
+
+#[allow(unused_macros)]
+macro_rules! assert_circle_approx {
+    ($a:expr, $b:expr, $epsilon:expr) => {
+        assert!(
+            $a.abs_diff_eq($b, $epsilon),
+            "circles not approximately equal: {:?} vs {:?} (epsilon {:?})",
+            $a,
+            $b,
+            $epsilon
+        );
+    };
+}
+
 /// Simple formula to calculate center point and radius of a circle from 2 points
 #[allow(dead_code)]
 pub fn circle_from_three_vertex(vertex: &Vec<Vertex, 3>) -> Circle {
@@ -150,10 +342,130 @@ pub fn circle_from_three_vertex(vertex: &Vec<Vertex, 3>) -> Circle {
     Circle {
         x: I16F16::from_num(-g),
         y: I16F16::from_num(-f),
-        r: I16F16::from_num(cordic::sqrt(sqr_of_r)),
+        r: I16F16::from_num(ops::sqrt(sqr_of_r)),
     }
 }
 
+/// Least-squares circle fit (Kasa method) over an arbitrary number of samples.
+///
+/// Fits the model `x^2 + y^2 = A*x + B*y + C` by forming and solving the 3x3
+/// normal-equation system, which is far more robust than
+/// `circle_from_three_vertex` when the samples are numerous, noisy, or nearly
+/// colinear. Returns `None` when the points are degenerate, i.e. the
+/// normal-equation determinant is ~0.
+#[allow(dead_code)]
+pub fn circle_from_vertices<const N: usize>(vertex: &Vec<Vertex, N>) -> Option<Circle> {
+    if vertex.is_empty() {
+        return None;
+    }
+
+    // Center on the centroid and rescale the spread to a fixed reference
+    // magnitude before accumulating. The normal-equation sums below are up
+    // to degree 3 in the input (e.g. `sum_xz ~ n*x^3`), so accumulating raw
+    // sensor-unit coordinates directly in I64F0 overflows for realistic
+    // sample sets (hundreds of points at thousand-unit amplitudes).
+    // Centering removes the absolute-position offset and the rescale bounds
+    // the spread itself, independent of the caller's units.
+    let mut mean_x = I32F32::from_num(0);
+    let mut mean_y = I32F32::from_num(0);
+    for v in vertex.iter() {
+        mean_x += I32F32::from_num(v.x);
+        mean_y += I32F32::from_num(v.y);
+    }
+    let count = I32F32::from_num(vertex.len());
+    mean_x /= count;
+    mean_y /= count;
+
+    let mut max_abs = I32F32::from_num(1);
+    for v in vertex.iter() {
+        let u = (I32F32::from_num(v.x) - mean_x).abs();
+        let w = (I32F32::from_num(v.y) - mean_y).abs();
+        max_abs = max_abs.max(u).max(w);
+    }
+    let scale = max_abs / I32F32::from_num(32);
+
+    // The Cramer's-rule terms below (det_a/det_b/det_c in particular) are
+    // products of three sums, some of them already cubic in the input,
+    // i.e. up to degree 6 overall. Centering/rescaling only pushes the
+    // overflow threshold out, it doesn't remove it -- a few thousand points
+    // (one revolution of real encoder counts) is enough to overflow even
+    // I64F0. Accumulate in f64 instead, which has enough range that this
+    // solve can't realistically overflow.
+    let mut sum_xx = 0f64;
+    let mut sum_xy = 0f64;
+    let mut sum_yy = 0f64;
+    let mut sum_x = 0f64;
+    let mut sum_y = 0f64;
+    let mut sum_xz = 0f64;
+    let mut sum_yz = 0f64;
+    let mut sum_z = 0f64;
+
+    for v in vertex.iter() {
+        let x = ((I32F32::from_num(v.x) - mean_x) / scale).to_num::<f64>();
+        let y = ((I32F32::from_num(v.y) - mean_y) / scale).to_num::<f64>();
+        let z = x * x + y * y;
+        sum_xx += x * x;
+        sum_xy += x * y;
+        sum_yy += y * y;
+        sum_x += x;
+        sum_y += y;
+        sum_xz += x * z;
+        sum_yz += y * z;
+        sum_z += z;
+    }
+    let n = vertex.len() as f64;
+
+    // Normal equations, solved by Cramer's rule, in the centered/scaled frame:
+    // [sum_xx sum_xy sum_x ] [A]   [sum_xz]
+    // [sum_xy sum_yy sum_y ] [B] = [sum_yz]
+    // [sum_x  sum_y  n     ] [C]   [sum_z ]
+    let det = sum_xx * (sum_yy * n - sum_y * sum_y) - sum_xy * (sum_xy * n - sum_y * sum_x)
+        + sum_x * (sum_xy * sum_y - sum_yy * sum_x);
+    // A near-zero (not just exactly zero) determinant means the samples are
+    // degenerate/near-colinear and the fit would be numerically unstable. `det`
+    // grows roughly with n^3 for well-conditioned samples (it's built from
+    // products of three sums-of-squares-ish accumulators), so scale the
+    // threshold the same way rather than using a fixed cutoff.
+    let det_epsilon = n * n * n * 1000.0;
+    if det.abs() < det_epsilon {
+        return None;
+    }
+
+    let det_a = sum_xz * (sum_yy * n - sum_y * sum_y) - sum_xy * (sum_yz * n - sum_y * sum_z)
+        + sum_x * (sum_yz * sum_y - sum_yy * sum_z);
+    let det_b = sum_xx * (sum_yz * n - sum_z * sum_y) - sum_xz * (sum_xy * n - sum_y * sum_x)
+        + sum_x * (sum_xy * sum_z - sum_yz * sum_x);
+    let det_c = sum_xx * (sum_yy * sum_z - sum_yz * sum_y)
+        - sum_xy * (sum_xy * sum_z - sum_yz * sum_x)
+        + sum_xz * (sum_xy * sum_y - sum_yy * sum_x);
+
+    let a = det_a / det;
+    let b = det_b / det;
+    let c = det_c / det;
+
+    // Center and radius in the centered/scaled frame, then undo the scale and
+    // centroid shift to land back in the caller's original units.
+    let cxp = a / 2.0;
+    let cyp = b / 2.0;
+    let sqr_of_r = c + cxp * cxp + cyp * cyp;
+    // Noisy/near-degenerate samples can still drive the least-squares fit to
+    // a negative sqr_of_r; ops::sqrt (cordic::sqrt in particular) has no
+    // negative-input guard and would spin forever, so bail out instead.
+    if sqr_of_r < 0.0 {
+        return None;
+    }
+
+    let cxp = I32F32::from_num(cxp);
+    let cyp = I32F32::from_num(cyp);
+    let r = ops::sqrt(I32F32::from_num(sqr_of_r));
+
+    Some(Circle {
+        x: I16F16::from_num(mean_x + scale * cxp),
+        y: I16F16::from_num(mean_y + scale * cyp),
+        r: I16F16::from_num(scale * r),
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -175,13 +487,14 @@ mod tests {
             y: fixed!(0.0),
         });
         let circ = circle_from_three_vertex(&buffer);
-        assert_eq!(
+        assert_circle_approx!(
             circ,
             Circle {
                 x: fixed!(0.0),
                 y: fixed!(0.0),
                 r: fixed!(1.0)
-            }
+            },
+            fixed!(0.01)
         );
         //println!("{:?}",circ);
     }
@@ -203,13 +516,14 @@ mod tests {
             y: fixed!(2.0),
         });
         let circ = circle_from_three_vertex(&buffer);
-        assert_eq!(
+        assert_circle_approx!(
             circ,
             Circle {
                 x: fixed!(2.0),
                 y: fixed!(2.0),
                 r: fixed!(3.0)
-            }
+            },
+            fixed!(0.01)
         );
         //println!("{:?}",circ);
     }
@@ -230,13 +544,14 @@ mod tests {
             y: fixed!(2.0),
         });
         let circ = circle_from_three_vertex(&buffer);
-        assert_eq!(
+        assert_circle_approx!(
             circ,
             Circle {
                 x: fixed!(2.0),
                 y: fixed!(2.0),
                 r: fixed!(1.0)
-            }
+            },
+            fixed!(0.01)
         );
         //println!("{:?}",circ);
     }
@@ -320,4 +635,365 @@ mod tests {
         //println!("{:?}", a*c);
         assert_eq!(c, b + a);
     }
+
+    #[test]
+    fn test_circle_from_vertices_on_unit_circle() {
+        let mut buffer = Vec::<Vertex, 4>::new();
+        let _ = buffer.push(Vertex {
+            x: fixed!(1.0),
+            y: fixed!(0.0),
+        });
+        let _ = buffer.push(Vertex {
+            x: fixed!(0.0),
+            y: fixed!(1.0),
+        });
+        let _ = buffer.push(Vertex {
+            x: fixed!(-1.0),
+            y: fixed!(0.0),
+        });
+        let _ = buffer.push(Vertex {
+            x: fixed!(0.0),
+            y: fixed!(-1.0),
+        });
+        let circ = circle_from_vertices(&buffer).unwrap();
+        assert_circle_approx!(
+            circ,
+            Circle {
+                x: fixed!(0.0),
+                y: fixed!(0.0),
+                r: fixed!(1.0)
+            },
+            fixed!(0.01)
+        );
+    }
+
+    #[test]
+    fn test_circle_from_vertices_degenerate_returns_none() {
+        let mut buffer = Vec::<Vertex, 3>::new();
+        let _ = buffer.push(Vertex {
+            x: fixed!(0.0),
+            y: fixed!(0.0),
+        });
+        let _ = buffer.push(Vertex {
+            x: fixed!(1.0),
+            y: fixed!(1.0),
+        });
+        let _ = buffer.push(Vertex {
+            x: fixed!(2.0),
+            y: fixed!(2.0),
+        });
+        assert_eq!(circle_from_vertices(&buffer), None);
+    }
+
+    #[test]
+    fn test_circle_from_vertices_near_colinear_returns_none() {
+        // Not exactly colinear (each point nudged off the `y = x` line by a
+        // tiny amount), but close enough that the fit is still degenerate.
+        let mut buffer = Vec::<Vertex, 5>::new();
+        let _ = buffer.push(Vertex {
+            x: fixed!(0.0),
+            y: fixed!(0.01),
+        });
+        let _ = buffer.push(Vertex {
+            x: fixed!(1.0),
+            y: fixed!(0.99),
+        });
+        let _ = buffer.push(Vertex {
+            x: fixed!(2.0),
+            y: fixed!(2.01),
+        });
+        let _ = buffer.push(Vertex {
+            x: fixed!(3.0),
+            y: fixed!(2.99),
+        });
+        let _ = buffer.push(Vertex {
+            x: fixed!(4.0),
+            y: fixed!(4.01),
+        });
+        assert_eq!(circle_from_vertices(&buffer), None);
+    }
+
+    #[test]
+    fn test_circle_from_vertices_does_not_overflow_for_large_noisy_sample_set() {
+        // A full revolution of noisy samples at realistic sensor amplitude and
+        // offset; this used to overflow the I64F0 accumulators and panic.
+        let circle = Circle {
+            x: fixed!(8000.0),
+            y: fixed!(-6000.0),
+            r: fixed!(1500.0),
+        };
+        let mut buffer = Vec::<Vertex, 200>::new();
+        for i in 0..200 {
+            let angle = Rad(fixed!(i) * fixed!(0.0314159));
+            let wobble = fixed!(if i % 2 == 0 { 3 } else { -3 });
+            let _ = buffer.push(circle.point_at(angle) + Vertex { x: wobble, y: wobble });
+        }
+        let circ = circle_from_vertices(&buffer).unwrap();
+        assert_circle_approx!(circ, circle, fixed!(50.0));
+    }
+
+    #[test]
+    fn test_circle_from_vertices_does_not_hang_for_several_revolutions_of_samples() {
+        // Same shape of input as the large-sample-set test above, but with
+        // enough points (several full revolutions worth, as a real rotary
+        // encoder would produce) that the Cramer's-rule terms used to
+        // overflow I64F0 and wrap into a fit with a negative sqr_of_r, which
+        // cordic::sqrt would then spin on forever.
+        let circle = Circle {
+            x: fixed!(8000.0),
+            y: fixed!(-6000.0),
+            r: fixed!(1500.0),
+        };
+        let mut buffer = Vec::<Vertex, 3500>::new();
+        for i in 0..3500 {
+            let angle = Rad(fixed!(i) * fixed!(0.0314159));
+            let wobble = fixed!(if i % 2 == 0 { 3 } else { -3 });
+            let _ = buffer.push(circle.point_at(angle) + Vertex { x: wobble, y: wobble });
+        }
+        let circ = circle_from_vertices(&buffer).unwrap();
+        assert_circle_approx!(circ, circle, fixed!(50.0));
+    }
+
+    #[test]
+    fn test_dot_product_for_vertex() {
+        let a = Vertex {
+            x: fixed!(1.0),
+            y: fixed!(2.0),
+        };
+        let b = Vertex {
+            x: fixed!(3.0),
+            y: fixed!(4.0),
+        };
+        assert_eq!(a.dot(b), fixed!(11.0));
+    }
+
+    #[test]
+    fn test_cross_product_for_vertex() {
+        let a = Vertex {
+            x: fixed!(1.0),
+            y: fixed!(0.0),
+        };
+        let b = Vertex {
+            x: fixed!(0.0),
+            y: fixed!(1.0),
+        };
+        assert_eq!(a.cross(b), fixed!(1.0));
+    }
+
+    #[test]
+    fn test_distance_for_vertex() {
+        let a = Vertex {
+            x: fixed!(0.0),
+            y: fixed!(0.0),
+        };
+        let b = Vertex {
+            x: fixed!(3.0),
+            y: fixed!(4.0),
+        };
+        // micromath's fast-approximate sqrt trades precision for speed, so it
+        // needs a wider tolerance than the exact cordic/libm backends.
+        #[cfg(feature = "micromath")]
+        let epsilon = fixed!(0.2);
+        #[cfg(not(feature = "micromath"))]
+        let epsilon = fixed!(0.001);
+        assert!((a.distance(b) - fixed!(5.0)).abs() <= epsilon);
+    }
+
+    #[test]
+    fn test_normalize_for_vertex() {
+        let a = Vertex {
+            x: fixed!(3.0),
+            y: fixed!(4.0),
+        };
+        let n = a.normalize().unwrap();
+        // micromath's fast-approximate sqrt trades precision for speed, so it
+        // needs a wider tolerance than the exact cordic/libm backends.
+        #[cfg(feature = "micromath")]
+        let epsilon = fixed!(0.05);
+        #[cfg(not(feature = "micromath"))]
+        let epsilon = fixed!(0.001);
+        assert!(n.abs_diff_eq(
+            Vertex {
+                x: fixed!(0.6),
+                y: fixed!(0.8),
+            },
+            epsilon
+        ));
+        let zero = Vertex {
+            x: fixed!(0.0),
+            y: fixed!(0.0),
+        };
+        assert_eq!(zero.normalize(), None);
+    }
+
+    #[test]
+    fn test_project_on_for_vertex() {
+        let a = Vertex {
+            x: fixed!(2.0),
+            y: fixed!(2.0),
+        };
+        let b = Vertex {
+            x: fixed!(1.0),
+            y: fixed!(0.0),
+        };
+        assert_eq!(
+            a.project_on(b),
+            Some(Vertex {
+                x: fixed!(2.0),
+                y: fixed!(0.0),
+            })
+        );
+
+        let zero = Vertex {
+            x: fixed!(0.0),
+            y: fixed!(0.0),
+        };
+        assert_eq!(a.project_on(zero), None);
+    }
+
+    #[test]
+    fn test_deg_to_rad_conversion() {
+        let rad: Rad = Deg(fixed!(180.0)).into();
+        assert_eq!(rad.0.round(), fixed!(3.0));
+    }
+
+    #[test]
+    fn test_vertex_angle() {
+        let a = Vertex {
+            x: fixed!(1.0),
+            y: fixed!(0.0),
+        };
+        assert_eq!(a.angle().0.round(), fixed!(0.0));
+    }
+
+    #[test]
+    fn test_vertex_from_angle() {
+        let v = Vertex::from_angle(Rad(fixed!(0.0)));
+        assert_eq!(v.x.round(), fixed!(1.0));
+        assert_eq!(v.y.round(), fixed!(0.0));
+    }
+
+    #[test]
+    fn test_vertex_rotate() {
+        let a = Vertex {
+            x: fixed!(1.0),
+            y: fixed!(0.0),
+        };
+        let rotated = a.rotate(Deg(fixed!(90.0)).into());
+        assert_eq!(rotated.x.round(), fixed!(0.0));
+        assert_eq!(rotated.y.round(), fixed!(1.0));
+    }
+
+    #[test]
+    fn test_circle_point_at() {
+        let c = Circle {
+            x: fixed!(1.0),
+            y: fixed!(1.0),
+            r: fixed!(2.0),
+        };
+        let p = c.point_at(Rad(fixed!(0.0)));
+        assert_eq!(p.x.round(), fixed!(3.0));
+        assert_eq!(p.y.round(), fixed!(1.0));
+    }
+
+    #[test]
+    fn test_vertex_abs_diff_eq() {
+        let a = Vertex {
+            x: fixed!(1.0),
+            y: fixed!(1.0),
+        };
+        let b = Vertex {
+            x: fixed!(1.005),
+            y: fixed!(0.995),
+        };
+        assert!(a.abs_diff_eq(b, fixed!(0.01)));
+        assert!(!a.abs_diff_eq(b, fixed!(0.001)));
+    }
+
+    #[test]
+    fn test_circle_relative_eq() {
+        let a = Circle {
+            x: fixed!(0.0),
+            y: fixed!(0.0),
+            r: fixed!(1000.0),
+        };
+        let b = Circle {
+            x: fixed!(0.0),
+            y: fixed!(0.0),
+            r: fixed!(1001.0),
+        };
+        assert!(a.relative_eq(b, fixed!(0.01), fixed!(0.01)));
+        assert!(!a.relative_eq(b, fixed!(0.01), fixed!(0.0001)));
+    }
+
+    #[test]
+    fn test_circle_bounding_box() {
+        let c = Circle {
+            x: fixed!(1.0),
+            y: fixed!(2.0),
+            r: fixed!(3.0),
+        };
+        assert_eq!(
+            c.bounding_box(),
+            Aabb2 {
+                min: Vertex {
+                    x: fixed!(-2.0),
+                    y: fixed!(-1.0),
+                },
+                max: Vertex {
+                    x: fixed!(4.0),
+                    y: fixed!(5.0),
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn test_aabb2_from_vertices() {
+        let mut buffer = Vec::<Vertex, 3>::new();
+        let _ = buffer.push(Vertex {
+            x: fixed!(1.0),
+            y: fixed!(5.0),
+        });
+        let _ = buffer.push(Vertex {
+            x: fixed!(-3.0),
+            y: fixed!(2.0),
+        });
+        let _ = buffer.push(Vertex {
+            x: fixed!(4.0),
+            y: fixed!(-1.0),
+        });
+        let aabb = Aabb2::from_vertices(&buffer).unwrap();
+        assert_eq!(
+            aabb,
+            Aabb2 {
+                min: Vertex {
+                    x: fixed!(-3.0),
+                    y: fixed!(-1.0),
+                },
+                max: Vertex {
+                    x: fixed!(4.0),
+                    y: fixed!(5.0),
+                },
+            }
+        );
+        assert!(aabb.contains(Vertex {
+            x: fixed!(0.0),
+            y: fixed!(0.0),
+        }));
+        assert!(!aabb.contains(Vertex {
+            x: fixed!(10.0),
+            y: fixed!(0.0),
+        }));
+        assert_eq!(
+            aabb.center(),
+            Vertex {
+                x: fixed!(0.5),
+                y: fixed!(2.0),
+            }
+        );
+
+        let empty = Vec::<Vertex, 3>::new();
+        assert_eq!(Aabb2::from_vertices(&empty), None);
+    }
 }
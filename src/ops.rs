@@ -0,0 +1,73 @@
+//! Deterministic transcendental math backend.
+//!
+//! `sqrt`/`atan2`/`sin_cos` are needed all over the decoder, but no single
+//! implementation is right for every target: CORDIC is exact fixed-point
+//! arithmetic and the only sane default for `no_std` MCUs, while hosts with
+//! `libm` or a fast-approximate path would rather trade some of that
+//! reproducibility for speed or for standard-library reuse. This module is
+//! the crate's single point of contact with the outside trig/sqrt world, so
+//! swapping backends only ever touches this file.
+//!
+//! Feature selection (mutually intended to be exclusive, `cordic` is the
+//! default):
+//! - `cordic` (default): pure fixed-point CORDIC, deterministic across targets.
+//! - `libm`: converts to `f32`, calls into `libm`, converts back.
+//! - `micromath`: converts to `f32`, uses `micromath`'s fast approximations.
+
+use fixed::traits::Fixed;
+use fixed::types::I16F16;
+
+/// Square root of a fixed-point value, in whatever fixed-point type it came in.
+pub fn sqrt<F: Fixed + cordic::CordicNumber>(val: F) -> F {
+    #[cfg(feature = "micromath")]
+    {
+        F::from_num(micromath::F32(val.to_num::<f32>()).sqrt().0)
+    }
+    #[cfg(all(feature = "libm", not(feature = "micromath")))]
+    {
+        F::from_num(libm::sqrtf(val.to_num::<f32>()))
+    }
+    #[cfg(all(feature = "cordic", not(any(feature = "libm", feature = "micromath"))))]
+    {
+        cordic::sqrt(val)
+    }
+}
+
+/// Four-quadrant arctangent, `atan2(y, x)`, in radians.
+pub fn atan2(y: I16F16, x: I16F16) -> I16F16 {
+    #[cfg(feature = "micromath")]
+    {
+        I16F16::from_num(
+            micromath::F32(y.to_num::<f32>())
+                .atan2(micromath::F32(x.to_num::<f32>()))
+                .0,
+        )
+    }
+    #[cfg(all(feature = "libm", not(feature = "micromath")))]
+    {
+        I16F16::from_num(libm::atan2f(y.to_num::<f32>(), x.to_num::<f32>()))
+    }
+    #[cfg(all(feature = "cordic", not(any(feature = "libm", feature = "micromath"))))]
+    {
+        cordic::atan2(y, x)
+    }
+}
+
+/// Simultaneous `(sin, cos)` of an angle in radians.
+pub fn sin_cos(angle: I16F16) -> (I16F16, I16F16) {
+    #[cfg(feature = "micromath")]
+    {
+        let a = micromath::F32(angle.to_num::<f32>());
+        (I16F16::from_num(a.sin().0), I16F16::from_num(a.cos().0))
+    }
+    #[cfg(all(feature = "libm", not(feature = "micromath")))]
+    {
+        let a = angle.to_num::<f32>();
+        let (sin, cos) = libm::sincosf(a);
+        (I16F16::from_num(sin), I16F16::from_num(cos))
+    }
+    #[cfg(all(feature = "cordic", not(any(feature = "libm", feature = "micromath"))))]
+    {
+        cordic::sin_cos(angle)
+    }
+}